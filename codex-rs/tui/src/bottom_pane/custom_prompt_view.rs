@@ -12,6 +12,9 @@ use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::Widget;
 use std::any::Any;
 use std::cell::RefCell;
+use std::ops::Range;
+use std::ops::RangeFrom;
+use std::path::PathBuf;
 
 use super::bottom_pane_view::BottomPaneView;
 use super::textarea::TextArea;
@@ -20,14 +23,88 @@ use super::textarea::TextAreaState;
 /// Callback invoked when the user submits a custom prompt.
 pub(crate) type PromptSubmitted = Box<dyn Fn(String) + Send + Sync>;
 
+/// Controls which Enter key combination submits the prompt versus inserting
+/// a newline, so a "newline" action is always reachable either way.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubmitBinding {
+    /// Plain Enter submits; any modified Enter (Ctrl/Alt/Shift) inserts a newline.
+    EnterSubmits,
+    /// Plain Enter inserts a newline; Ctrl/Alt/Shift-Enter submits.
+    ModEnterSubmits,
+}
+
+/// Maximum number of past prompts kept in a [`PromptHistory`].
+const MAX_HISTORY_ENTRIES: usize = 200;
+
+/// Separates entries in the on-disk history file. Prompts are free-form,
+/// possibly multi-line text, so a newline can't be used as the delimiter;
+/// NUL cannot appear in a prompt typed through the textarea.
+const HISTORY_ENTRY_DELIMITER: &str = "\0";
+
+/// Ring of previously submitted prompts, persisted to disk between sessions.
+pub(crate) struct PromptHistory {
+    path: PathBuf,
+    entries: Vec<String>,
+}
+
+impl PromptHistory {
+    /// Loads the history ring from `path`, treating a missing or unreadable
+    /// file as an empty history rather than an error.
+    pub(crate) fn load(path: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .filter(|contents| !contents.is_empty())
+            .map(|contents| {
+                contents
+                    .split(HISTORY_ENTRY_DELIMITER)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { path, entries }
+    }
+
+    /// Appends `entry` as the most recent submission and persists the ring,
+    /// dropping an earlier duplicate so recall doesn't show the same text twice.
+    fn push(&mut self, entry: String) {
+        self.entries.retain(|existing| existing != &entry);
+        self.entries.push(entry);
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let overflow = self.entries.len() - MAX_HISTORY_ENTRIES;
+            self.entries.drain(0..overflow);
+        }
+        let _ = std::fs::write(&self.path, self.entries.join(HISTORY_ENTRY_DELIMITER));
+    }
+}
+
+/// Maps the current line prefix (up to the cursor) to completion candidates,
+/// each paired with the byte range of the prefix they would replace.
+pub(crate) type CompletionFn = Box<dyn Fn(&str) -> Vec<(RangeFrom<usize>, String)>>;
+
+/// Returns a one-line documentation hint for the first word of a candidate.
+pub(crate) type DocFn = Box<dyn Fn(&str) -> Option<String>>;
+
+/// Number of completion candidates shown at once before scrolling.
+const MAX_VISIBLE_COMPLETIONS: usize = 6;
+
 /// Minimal multi-line text input view to collect custom review instructions.
 pub(crate) struct CustomPromptView {
     title: String,
     on_submit: PromptSubmitted,
+    completion_fn: CompletionFn,
+    doc_fn: DocFn,
+    history: PromptHistory,
+    submit_binding: SubmitBinding,
 
     // UI state
     textarea: TextArea,
     textarea_state: RefCell<TextAreaState>,
+    candidates: Vec<(Range<usize>, String)>,
+    selection: Option<usize>,
+    /// Index into `history.entries` currently shown, or `None` if editing the draft.
+    history_index: Option<usize>,
+    /// Snapshot of the in-progress draft taken when history recall begins.
+    draft: Option<String>,
     complete: bool,
 }
 
@@ -40,15 +117,230 @@ impl CustomPromptView {
         Paragraph::new(Line::from("▌ ".dim())).render(area, buf);
     }
 
-    pub(crate) fn new(title: String, on_submit: PromptSubmitted) -> Self {
+    pub(crate) fn new(
+        title: String,
+        on_submit: PromptSubmitted,
+        completion_fn: CompletionFn,
+        doc_fn: DocFn,
+        history: PromptHistory,
+        submit_binding: SubmitBinding,
+    ) -> Self {
         Self {
             title,
             on_submit,
+            completion_fn,
+            doc_fn,
+            history,
+            submit_binding,
             textarea: TextArea::new(),
             textarea_state: RefCell::new(TextAreaState::default()),
+            candidates: Vec::new(),
+            selection: None,
+            history_index: None,
+            draft: None,
             complete: false,
         }
     }
+
+    /// Whether the cursor sits on the first line of the textarea.
+    fn cursor_on_first_line(&self) -> bool {
+        self.textarea.cursor_row() == 0
+    }
+
+    /// Whether the cursor sits on the last line of the textarea.
+    fn cursor_on_last_line(&self) -> bool {
+        self.textarea.cursor_row() + 1 == self.textarea.line_count()
+    }
+
+    /// Recalls the previous (older) history entry, snapshotting the draft first.
+    fn recall_previous(&mut self) {
+        if self.history.entries.is_empty() {
+            return;
+        }
+        let next_index = match self.history_index {
+            None => self.history.entries.len() - 1,
+            Some(0) => 0,
+            Some(index) => index - 1,
+        };
+        if self.history_index.is_none() {
+            self.draft = Some(self.textarea.text());
+        }
+        self.history_index = Some(next_index);
+        self.textarea.set_text(&self.history.entries[next_index]);
+        self.update_completions();
+    }
+
+    /// Recalls the next (newer) history entry, or restores the draft once past the end.
+    fn recall_next(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        if index + 1 < self.history.entries.len() {
+            self.history_index = Some(index + 1);
+            self.textarea.set_text(&self.history.entries[index + 1]);
+        } else {
+            self.history_index = None;
+            self.textarea
+                .set_text(&self.draft.take().unwrap_or_default());
+        }
+        self.update_completions();
+    }
+
+    /// Whether a completion dropdown is currently being shown.
+    fn is_completing(&self) -> bool {
+        !self.candidates.is_empty()
+    }
+
+    /// Whether this Enter key combination should submit under the active binding.
+    fn is_submit_key(&self, key_event: KeyEvent) -> bool {
+        match self.submit_binding {
+            SubmitBinding::EnterSubmits => key_event.modifiers == KeyModifiers::NONE,
+            SubmitBinding::ModEnterSubmits => key_event
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT),
+        }
+    }
+
+    /// The hint line reflecting the active submit binding.
+    fn hint_line(&self) -> Line<'static> {
+        match self.submit_binding {
+            SubmitBinding::EnterSubmits => super::standard_popup_hint_line(),
+            SubmitBinding::ModEnterSubmits => {
+                Line::from("Enter newline · Ctrl+Enter submit · Esc cancel".dim())
+            }
+        }
+    }
+
+    /// The current line up to the cursor, if it starts with `/` or `@`, along
+    /// with the document offsets at which that line and the cursor itself sit.
+    fn current_trigger_prefix(&self) -> Option<(String, usize, usize)> {
+        let text = self.textarea.text();
+        let cursor = self.textarea.cursor().min(text.len());
+        let line_start = text[..cursor].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line = &text[line_start..cursor];
+        if line.starts_with('/') || line.starts_with('@') {
+            Some((line.to_string(), line_start, cursor))
+        } else {
+            None
+        }
+    }
+
+    /// Recomputes `candidates` from the current cursor position; called after every edit.
+    ///
+    /// `completion_fn` only ever sees the current line's prefix, so the
+    /// `RangeFrom` it returns is relative to that prefix (0 = the `/`/`@`,
+    /// open-ended because it doesn't know where the prefix ends in the
+    /// document). Anchor that start at `line_start` and bound the end at the
+    /// cursor — not at the end of the buffer — so `apply_selected_completion`
+    /// only ever replaces the trigger text itself, leaving anything after the
+    /// cursor untouched.
+    fn update_completions(&mut self) {
+        self.candidates = match self.current_trigger_prefix() {
+            Some((prefix, line_start, cursor)) if !prefix.is_empty() => {
+                (self.completion_fn)(&prefix)
+                    .into_iter()
+                    .map(|(range, replacement)| (line_start + range.start..cursor, replacement))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        self.selection = if self.candidates.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
+    fn cycle_selection(&mut self, delta: isize) {
+        if self.candidates.is_empty() {
+            return;
+        }
+        let len = self.candidates.len() as isize;
+        let current = self.selection.map(|s| s as isize).unwrap_or(0);
+        self.selection = Some((current + delta).rem_euclid(len) as usize);
+    }
+
+    fn apply_selected_completion(&mut self) {
+        let Some(index) = self.selection else {
+            return;
+        };
+        let Some((range, replacement)) = self.candidates.get(index).cloned() else {
+            return;
+        };
+        self.textarea.replace_range(range, &replacement);
+        self.candidates.clear();
+        self.selection = None;
+    }
+
+    fn selected_doc(&self) -> Option<String> {
+        let index = self.selection?;
+        let (_, replacement) = self.candidates.get(index)?;
+        let first_word = replacement.split_whitespace().next().unwrap_or(replacement);
+        (self.doc_fn)(first_word)
+    }
+
+    /// Rows needed to show the completion dropdown plus its doc line, if any.
+    fn completion_rows(&self) -> u16 {
+        if self.candidates.is_empty() {
+            0
+        } else {
+            self.candidates.len().min(MAX_VISIBLE_COMPLETIONS) as u16 + 1
+        }
+    }
+
+    /// Index of the first candidate shown, scrolled so the selected row stays
+    /// within the visible window once there are more candidates than fit.
+    fn completion_scroll_start(&self) -> usize {
+        let total = self.candidates.len();
+        if total <= MAX_VISIBLE_COMPLETIONS {
+            return 0;
+        }
+        let selected = self.selection.unwrap_or(0);
+        selected
+            .saturating_sub(MAX_VISIBLE_COMPLETIONS - 1)
+            .min(total - MAX_VISIBLE_COMPLETIONS)
+    }
+
+    fn render_completions(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+        Clear.render(area, buf);
+        let visible = self.candidates.len().min(MAX_VISIBLE_COMPLETIONS);
+        let start = self.completion_scroll_start();
+        for (row, (_, replacement)) in self.candidates.iter().skip(start).take(visible).enumerate()
+        {
+            let label = format!("  {replacement}");
+            let line = if self.selection == Some(start + row) {
+                Line::from(label.reversed())
+            } else {
+                Line::from(label.dim())
+            };
+            Paragraph::new(line).render(
+                Rect {
+                    x: area.x,
+                    y: area.y.saturating_add(row as u16),
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+        if let Some(doc) = self.selected_doc() {
+            let doc_y = area.y.saturating_add(visible as u16);
+            if doc_y < area.y.saturating_add(area.height) {
+                Paragraph::new(Line::from(format!("  {doc}").italic())).render(
+                    Rect {
+                        x: area.x,
+                        y: doc_y,
+                        width: area.width,
+                        height: 1,
+                    },
+                    buf,
+                );
+            }
+        }
+    }
 }
 
 impl BottomPaneView for CustomPromptView {
@@ -61,27 +353,68 @@ impl BottomPaneView for CustomPromptView {
             KeyEvent {
                 code: KeyCode::Esc, ..
             } => {
-                self.complete = true;
+                if self.is_completing() {
+                    self.candidates.clear();
+                    self.selection = None;
+                } else {
+                    self.complete = true;
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Tab, ..
+            } if self.is_completing() => {
+                self.cycle_selection(1);
+            }
+            KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            } if self.is_completing() => {
+                self.cycle_selection(-1);
             }
             KeyEvent {
                 code: KeyCode::Enter,
-                modifiers: KeyModifiers::NONE,
                 ..
-            } => {
+            } if self.is_completing() && self.is_submit_key(key_event) => {
+                self.apply_selected_completion();
+            }
+            KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            } if self.is_submit_key(key_event) => {
                 let text = self.textarea.text().trim().to_string();
                 if !text.is_empty() {
+                    self.history.push(text.clone());
+                    self.history_index = None;
+                    self.draft = None;
                     (self.on_submit)(text);
                 }
                 self.complete = true;
             }
+            KeyEvent {
+                code: KeyCode::Up,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if self.cursor_on_first_line() => {
+                self.recall_previous();
+            }
+            KeyEvent {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if self.cursor_on_last_line() => {
+                self.recall_next();
+            }
             KeyEvent {
                 code: KeyCode::Enter,
                 ..
             } => {
+                // Explicit newline action, always reachable regardless of binding.
                 self.textarea.input(key_event);
+                self.update_completions();
             }
             other => {
                 self.textarea.input(other);
+                self.update_completions();
             }
         }
     }
@@ -91,7 +424,7 @@ impl BottomPaneView for CustomPromptView {
     }
 
     fn desired_height(&self, width: u16) -> u16 {
-        1 + self.input_height(width) + 2
+        1 + self.input_height(width) + 2 + self.completion_rows()
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
@@ -111,10 +444,26 @@ impl BottomPaneView for CustomPromptView {
         let title_spans: Vec<Span<'static>> = vec!["▌ ".dim(), self.title.clone().bold()];
         Paragraph::new(Line::from(title_spans)).render(title_area, buf);
 
+        // Completion dropdown + doc line, rendered above the input (Helix-style)
+        // so it sits right over the trigger text the user is typing.
+        let completion_y = area.y.saturating_add(1);
+        let completion_height = self.completion_rows();
+        if completion_height > 0 {
+            self.render_completions(
+                Rect {
+                    x: area.x,
+                    y: completion_y,
+                    width: area.width,
+                    height: completion_height,
+                },
+                buf,
+            );
+        }
+
         // Input line
         let input_area = Rect {
             x: area.x,
-            y: area.y.saturating_add(1),
+            y: completion_y.saturating_add(completion_height),
             width: area.width,
             height: input_height,
         };
@@ -157,7 +506,7 @@ impl BottomPaneView for CustomPromptView {
             }
         }
 
-        let hint_blank_y = area.y.saturating_add(1).saturating_add(input_height);
+        let hint_blank_y = input_area.y.saturating_add(input_height);
         if hint_blank_y < area.y.saturating_add(area.height) {
             let blank_area = Rect {
                 x: area.x,
@@ -169,7 +518,7 @@ impl BottomPaneView for CustomPromptView {
         }
         let hint_y = hint_blank_y.saturating_add(1);
         if hint_y < area.y.saturating_add(area.height) {
-            Paragraph::new(super::standard_popup_hint_line()).render(
+            Paragraph::new(self.hint_line()).render(
                 Rect {
                     x: area.x,
                     y: hint_y,
@@ -186,6 +535,7 @@ impl BottomPaneView for CustomPromptView {
             return false;
         }
         self.textarea.insert_str(&pasted);
+        self.update_completions();
         true
     }
 
@@ -199,7 +549,10 @@ impl BottomPaneView for CustomPromptView {
         }
         let textarea_rect = Rect {
             x: area.x.saturating_add(2),
-            y: area.y.saturating_add(2),
+            y: area
+                .y
+                .saturating_add(2)
+                .saturating_add(self.completion_rows()),
             width: area.width.saturating_sub(2),
             height: text_area_height,
         };
@@ -215,3 +568,163 @@ impl CustomPromptView {
         text_height.saturating_add(1).min(9)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A process-unique temp file path so concurrent test runs don't collide.
+    fn history_test_path(tag: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "codex-custom-prompt-history-{tag}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        path
+    }
+
+    #[test]
+    fn history_round_trips_multiline_entries() {
+        let path = history_test_path("roundtrip");
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = PromptHistory::load(path.clone());
+        history.push("line a\nline b".to_string());
+        history.push("single line".to_string());
+
+        let reloaded = PromptHistory::load(path.clone());
+        assert_eq!(
+            reloaded.entries,
+            vec!["line a\nline b".to_string(), "single line".to_string()]
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn apply_selected_completion_offsets_range_by_line_start() {
+        let completion_fn: CompletionFn = Box::new(|_prefix| vec![(0.., "/review".to_string())]);
+        let path = history_test_path("completion");
+        let _ = std::fs::remove_file(&path);
+
+        let mut view = CustomPromptView::new(
+            "Title".to_string(),
+            Box::new(|_| {}),
+            completion_fn,
+            Box::new(|_| None),
+            PromptHistory::load(path.clone()),
+            SubmitBinding::EnterSubmits,
+        );
+        view.textarea.set_text("hello\n/re");
+        view.update_completions();
+        view.apply_selected_completion();
+
+        assert_eq!(view.textarea.text(), "hello\n/review");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Regression test for a non-zero `line_start` combined with text after
+    /// the cursor (a trailing word on the same line, plus a following line),
+    /// which an open-ended `RangeFrom` would previously wipe out.
+    #[test]
+    fn apply_selected_completion_preserves_text_after_the_cursor() {
+        let completion_fn: CompletionFn = Box::new(|_prefix| vec![(0.., "/review".to_string())]);
+        let path = history_test_path("completion-trailing");
+        let _ = std::fs::remove_file(&path);
+
+        let mut view = CustomPromptView::new(
+            "Title".to_string(),
+            Box::new(|_| {}),
+            completion_fn,
+            Box::new(|_| None),
+            PromptHistory::load(path.clone()),
+            SubmitBinding::EnterSubmits,
+        );
+        let trailing = " foo\nplease be thorough";
+        view.textarea.set_text(&format!("hello\n/rev{trailing}"));
+        for _ in trailing.chars() {
+            view.textarea
+                .input(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        }
+        view.update_completions();
+        view.apply_selected_completion();
+
+        assert_eq!(view.textarea.text(), format!("hello\n/review{trailing}"));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    fn view_with_binding(submit_binding: SubmitBinding) -> CustomPromptView {
+        let path = history_test_path("submit-binding");
+        let _ = std::fs::remove_file(&path);
+        CustomPromptView::new(
+            "Title".to_string(),
+            Box::new(|_| {}),
+            Box::new(|_| Vec::new()),
+            Box::new(|_| None),
+            PromptHistory::load(path),
+            submit_binding,
+        )
+    }
+
+    #[test]
+    fn is_submit_key_dispatches_plain_enter_under_enter_submits() {
+        let view = view_with_binding(SubmitBinding::EnterSubmits);
+        assert!(view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT)));
+        assert!(!view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn is_submit_key_dispatches_modified_enter_under_mod_enter_submits() {
+        let view = view_with_binding(SubmitBinding::ModEnterSubmits);
+        assert!(!view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)));
+        assert!(view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT)));
+        assert!(view.is_submit_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT)));
+    }
+
+    fn view_with_candidate_count(count: usize) -> CustomPromptView {
+        let completion_fn: CompletionFn =
+            Box::new(move |_prefix| (0..count).map(|i| (0.., format!("/cmd{i}"))).collect());
+        let path = history_test_path("completion-scroll");
+        let _ = std::fs::remove_file(&path);
+        let mut view = CustomPromptView::new(
+            "Title".to_string(),
+            Box::new(|_| {}),
+            completion_fn,
+            Box::new(|_| None),
+            PromptHistory::load(path),
+            SubmitBinding::EnterSubmits,
+        );
+        view.textarea.set_text("/c");
+        view.update_completions();
+        view
+    }
+
+    #[test]
+    fn completion_scroll_start_keeps_selection_within_visible_window() {
+        let mut view = view_with_candidate_count(MAX_VISIBLE_COMPLETIONS + 4);
+        assert_eq!(view.completion_scroll_start(), 0);
+
+        view.selection = Some(MAX_VISIBLE_COMPLETIONS + 2);
+        let start = view.completion_scroll_start();
+        assert!(
+            start <= view.selection.unwrap()
+                && view.selection.unwrap() < start + MAX_VISIBLE_COMPLETIONS
+        );
+
+        view.selection = Some(view.candidates.len() - 1);
+        let start = view.completion_scroll_start();
+        assert_eq!(start, view.candidates.len() - MAX_VISIBLE_COMPLETIONS);
+    }
+
+    #[test]
+    fn completion_scroll_start_is_zero_when_everything_fits() {
+        let mut view = view_with_candidate_count(MAX_VISIBLE_COMPLETIONS);
+        view.selection = Some(MAX_VISIBLE_COMPLETIONS - 1);
+        assert_eq!(view.completion_scroll_start(), 0);
+    }
+}
@@ -0,0 +1,424 @@
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use ratatui::widgets::Clear;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Widget;
+use std::any::Any;
+
+use super::bottom_pane_view::BottomPaneView;
+use super::custom_prompt_view::PromptSubmitted;
+
+/// Number of templates shown at once before scrolling.
+const MAX_VISIBLE_TEMPLATES: usize = 8;
+
+/// A saved review-instruction template offered by the picker.
+pub(crate) struct Template {
+    pub(crate) name: String,
+    pub(crate) body: String,
+}
+
+/// Renders a [`Template`] as the string the fuzzy matcher and list row use.
+pub(crate) type FormatFn = Box<dyn Fn(&Template) -> String>;
+
+struct ScoredTemplate {
+    index: usize,
+    score: i32,
+    match_span: usize,
+    positions: Vec<usize>,
+}
+
+/// Fuzzy-searchable list of saved review-instruction templates.
+///
+/// Typing filters `options` with a Skim-style subsequence matcher; Enter submits
+/// the highlighted template directly, Tab hands it off for editing instead.
+pub(crate) struct PromptTemplatePickerView {
+    title: String,
+    options: Vec<Template>,
+    format_fn: FormatFn,
+    on_submit: PromptSubmitted,
+    on_edit: PromptSubmitted,
+
+    query: String,
+    filtered: Vec<ScoredTemplate>,
+    selected: usize,
+    complete: bool,
+}
+
+impl PromptTemplatePickerView {
+    pub(crate) fn new(
+        title: String,
+        options: Vec<Template>,
+        format_fn: FormatFn,
+        on_submit: PromptSubmitted,
+        on_edit: PromptSubmitted,
+    ) -> Self {
+        let mut view = Self {
+            title,
+            options,
+            format_fn,
+            on_submit,
+            on_edit,
+            query: String::new(),
+            filtered: Vec::new(),
+            selected: 0,
+            complete: false,
+        };
+        view.refilter();
+        view
+    }
+
+    fn refilter(&mut self) {
+        let mut scored: Vec<ScoredTemplate> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, template)| {
+                let haystack = (self.format_fn)(template);
+                fuzzy_match(&haystack, &self.query).map(|(score, match_span, positions)| {
+                    ScoredTemplate {
+                        index,
+                        score,
+                        match_span,
+                        positions,
+                    }
+                })
+            })
+            .collect();
+        scored.sort_by(|a, b| b.score.cmp(&a.score).then(a.match_span.cmp(&b.match_span)));
+        self.filtered = scored;
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        let len = self.filtered.len() as isize;
+        let current = self.selected as isize;
+        self.selected = (current + delta).rem_euclid(len) as usize;
+    }
+
+    fn selected_template(&self) -> Option<&Template> {
+        let scored = self.filtered.get(self.selected)?;
+        self.options.get(scored.index)
+    }
+
+    /// Index of the first template shown, scrolled so `selected` stays within
+    /// the visible window once there are more matches than fit.
+    fn scroll_start(&self) -> usize {
+        let total = self.filtered.len();
+        if total <= MAX_VISIBLE_TEMPLATES {
+            return 0;
+        }
+        self.selected
+            .saturating_sub(MAX_VISIBLE_TEMPLATES - 1)
+            .min(total - MAX_VISIBLE_TEMPLATES)
+    }
+
+    fn submit_selected(&mut self) {
+        if let Some(template) = self.selected_template() {
+            (self.on_submit)(template.body.clone());
+        }
+        self.complete = true;
+    }
+
+    fn edit_selected(&mut self) {
+        if let Some(template) = self.selected_template() {
+            (self.on_edit)(template.body.clone());
+        }
+        self.complete = true;
+    }
+}
+
+/// Greedy subsequence match of `needle` against `haystack`, scoring consecutive
+/// runs and start-of-word hits higher, à la Skim's fuzzy matcher. Returns the
+/// score, the span covered by the match, and the matched char indices, or
+/// `None` if `needle` is not a subsequence of `haystack`.
+fn fuzzy_match(haystack: &str, needle: &str) -> Option<(i32, usize, Vec<usize>)> {
+    if needle.is_empty() {
+        return Some((0, 0, Vec::new()));
+    }
+
+    let haystack_chars: Vec<char> = haystack.chars().collect();
+    let mut needle_chars = needle.chars().map(|c| c.to_ascii_lowercase());
+    let mut current = needle_chars.next()?;
+
+    let mut positions = Vec::new();
+    let mut score = 0i32;
+    let mut last_match: Option<usize> = None;
+    let mut first_match: Option<usize> = None;
+
+    for (i, &ch) in haystack_chars.iter().enumerate() {
+        if ch.to_ascii_lowercase() != current {
+            continue;
+        }
+        positions.push(i);
+        first_match.get_or_insert(i);
+        score += 16;
+        let starts_word = i == 0 || matches!(haystack_chars[i - 1], ' ' | '_' | '-' | '/' | '.');
+        if starts_word {
+            score += 8;
+        }
+        if last_match == Some(i.wrapping_sub(1)) {
+            score += 12;
+        }
+        last_match = Some(i);
+        current = match needle_chars.next() {
+            Some(c) => c,
+            None => break,
+        };
+    }
+
+    if positions.len() < needle.chars().count() {
+        return None;
+    }
+    let match_span = last_match.unwrap_or(0) - first_match.unwrap_or(0) + 1;
+    Some((score, match_span, positions))
+}
+
+impl BottomPaneView for PromptTemplatePickerView {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn handle_key_event(&mut self, _pane: &mut super::BottomPane, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Esc => self.complete = true,
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Enter => self.submit_selected(),
+            KeyCode::Tab => self.edit_selected(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refilter();
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.refilter();
+            }
+            _ => {}
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn desired_height(&self, _width: u16) -> u16 {
+        // title + query line + 1..=N rows (or the empty-list message) + blank + hint
+        1 + 1 + self.filtered.len().clamp(1, MAX_VISIBLE_TEMPLATES) as u16 + 2
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let title_spans: Vec<Span<'static>> = vec!["▌ ".dim(), self.title.clone().bold()];
+        Paragraph::new(Line::from(title_spans)).render(
+            Rect {
+                x: area.x,
+                y: area.y,
+                width: area.width,
+                height: 1,
+            },
+            buf,
+        );
+
+        let query_line = Line::from(vec!["▌ ".dim(), format!("/ {}", self.query).into()]);
+        Paragraph::new(query_line).render(
+            Rect {
+                x: area.x,
+                y: area.y.saturating_add(1),
+                width: area.width,
+                height: 1,
+            },
+            buf,
+        );
+
+        let visible = self.filtered.len().min(MAX_VISIBLE_TEMPLATES);
+        let start = self.scroll_start();
+        for row in 0..visible {
+            let scored = &self.filtered[start + row];
+            let template = &self.options[scored.index];
+            let label = (self.format_fn)(template);
+            let spans = highlight_spans(&label, &scored.positions);
+            let row_y = area.y.saturating_add(2).saturating_add(row as u16);
+            if row_y >= area.y.saturating_add(area.height) {
+                break;
+            }
+            let mut line_spans: Vec<Span<'static>> = vec!["▌ ".dim()];
+            line_spans.extend(spans);
+            let line = if start + row == self.selected {
+                Line::from(line_spans).reversed()
+            } else {
+                Line::from(line_spans)
+            };
+            Paragraph::new(line).render(
+                Rect {
+                    x: area.x,
+                    y: row_y,
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+        if self.filtered.is_empty() {
+            let empty_y = area.y.saturating_add(2);
+            if empty_y < area.y.saturating_add(area.height) {
+                Paragraph::new(Line::from("No matching templates".dim())).render(
+                    Rect {
+                        x: area.x,
+                        y: empty_y,
+                        width: area.width,
+                        height: 1,
+                    },
+                    buf,
+                );
+            }
+        }
+
+        let hint_y = area
+            .y
+            .saturating_add(2)
+            .saturating_add(visible.max(1) as u16)
+            .saturating_add(1);
+        if hint_y < area.y.saturating_add(area.height) {
+            Clear.render(
+                Rect {
+                    x: area.x,
+                    y: hint_y,
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+            Paragraph::new(Line::from(
+                "↑↓ select · Enter submit · Tab edit · Esc cancel".dim(),
+            ))
+            .render(
+                Rect {
+                    x: area.x,
+                    y: hint_y,
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+    }
+}
+
+/// Splits `label` into spans, bolding the characters at `positions`.
+fn highlight_spans(label: &str, positions: &[usize]) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    for (i, ch) in label.chars().enumerate() {
+        if positions.binary_search(&i).is_ok() {
+            if !plain.is_empty() {
+                spans.push(Span::raw(std::mem::take(&mut plain)));
+            }
+            spans.push(Span::from(ch.to_string()).bold());
+        } else {
+            plain.push(ch);
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_rejects_non_subsequence() {
+        assert!(fuzzy_match("review instructions", "xyz").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_empty_needle_matches_everything() {
+        assert_eq!(fuzzy_match("anything", ""), Some((0, 0, Vec::new())));
+    }
+
+    #[test]
+    fn fuzzy_match_scores_consecutive_start_of_word_hits_higher() {
+        let (contiguous_score, _, _) = fuzzy_match("review", "rev").unwrap();
+        let (scattered_score, _, _) = fuzzy_match("r-e-v", "rev").unwrap();
+        assert!(
+            contiguous_score > scattered_score,
+            "contiguous start-of-word match ({contiguous_score}) should outscore a scattered one ({scattered_score})"
+        );
+    }
+
+    #[test]
+    fn refilter_orders_best_scoring_template_first() {
+        let options = vec![
+            Template {
+                name: "security review".to_string(),
+                body: "sec".to_string(),
+            },
+            Template {
+                name: "review".to_string(),
+                body: "rev".to_string(),
+            },
+        ];
+        let mut view = PromptTemplatePickerView::new(
+            "Title".to_string(),
+            options,
+            Box::new(|template| template.name.clone()),
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+        );
+        view.query = "review".to_string();
+        view.refilter();
+
+        let top = view.selected_template().expect("at least one match");
+        assert_eq!(top.name, "review");
+    }
+
+    fn view_with_templates(count: usize) -> PromptTemplatePickerView {
+        let options = (0..count)
+            .map(|i| Template {
+                name: format!("template-{i}"),
+                body: format!("body-{i}"),
+            })
+            .collect();
+        PromptTemplatePickerView::new(
+            "Title".to_string(),
+            options,
+            Box::new(|template| template.name.clone()),
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn scroll_start_keeps_selection_within_visible_window() {
+        let mut view = view_with_templates(MAX_VISIBLE_TEMPLATES + 4);
+        assert_eq!(view.scroll_start(), 0);
+
+        view.selected = MAX_VISIBLE_TEMPLATES + 2;
+        let start = view.scroll_start();
+        assert!(start <= view.selected && view.selected < start + MAX_VISIBLE_TEMPLATES);
+
+        view.selected = view.filtered.len() - 1;
+        let start = view.scroll_start();
+        assert_eq!(start, view.filtered.len() - MAX_VISIBLE_TEMPLATES);
+    }
+
+    #[test]
+    fn scroll_start_is_zero_when_everything_fits() {
+        let mut view = view_with_templates(MAX_VISIBLE_TEMPLATES);
+        view.selected = MAX_VISIBLE_TEMPLATES - 1;
+        assert_eq!(view.scroll_start(), 0);
+    }
+}